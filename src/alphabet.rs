@@ -0,0 +1,234 @@
+//! Configurable alphabets that pack more than one bit per emitted character, for a denser
+//! encoding than the default 2-symbol (`HIGH`/`LOW`) scheme.
+
+use crate::DecodeError;
+
+/// An ordered set of whitespace/invisible code points used to encode `log2(len)` bits per
+/// character. The length of the alphabet must be a power of two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    symbols: Vec<char>,
+    bits_per_symbol: u32,
+}
+
+impl Alphabet {
+    /// Creates a new `Alphabet` from `symbols`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `symbols` is empty or its length is not a power of two.
+    pub fn new(symbols: Vec<char>) -> Self {
+        assert!(!symbols.is_empty(), "alphabet must not be empty");
+        assert!(
+            symbols.len().is_power_of_two(),
+            "alphabet length must be a power of two"
+        );
+
+        let bits_per_symbol = symbols.len().trailing_zeros();
+
+        Alphabet {
+            symbols,
+            bits_per_symbol,
+        }
+    }
+
+    /// The number of bits each symbol of this alphabet represents.
+    pub fn bits_per_symbol(&self) -> u32 {
+        self.bits_per_symbol
+    }
+
+    fn index_of(&self, char: char) -> Option<usize> {
+        self.symbols.iter().position(|&symbol| symbol == char)
+    }
+}
+
+/// The default 2-symbol alphabet used by [`crate::encode`] and [`crate::decode`]: 1 bit/char.
+pub fn default_alphabet() -> Alphabet {
+    Alphabet::new(vec![crate::LOW, crate::HIGH])
+}
+
+/// The 4-symbol alphabet: space, tab, zero-width space and zero-width non-joiner, 2 bits/char.
+pub fn base4_alphabet() -> Alphabet {
+    Alphabet::new(vec!['\u{0020}', '\u{0009}', '\u{200b}', '\u{200c}'])
+}
+
+/// The 8-symbol alphabet: extends [`base4_alphabet`] with zero-width joiner and three more
+/// invisible code points, 3 bits/char.
+pub fn base8_alphabet() -> Alphabet {
+    Alphabet::new(vec![
+        '\u{0020}',
+        '\u{0009}',
+        '\u{200b}',
+        '\u{200c}',
+        '\u{200d}',
+        '\u{feff}',
+        '\u{2060}',
+        '\u{2061}',
+    ])
+}
+
+/// Encodes `data` using `alphabet`, packing `alphabet.bits_per_symbol()` bits into each emitted
+/// character.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whitespace::alphabet::{base4_alphabet, encode_with, decode_with};
+///
+/// let alphabet = base4_alphabet();
+/// let data = vec![10, 10];
+/// let encoded = encode_with(&alphabet, &data);
+///
+/// assert_eq!(Ok(data), decode_with(&alphabet, &encoded));
+/// ```
+pub fn encode_with(alphabet: &Alphabet, data: &[u8]) -> String {
+    let bits_per_symbol = alphabet.bits_per_symbol();
+    let symbol_mask = (1u32 << bits_per_symbol) - 1;
+
+    let mut acc = 0u32;
+    let mut acc_bits = 0u32;
+    let mut out = String::with_capacity(data.len() * 8 / bits_per_symbol as usize + 1);
+
+    for &byte in data {
+        acc = (acc << 8) | u32::from(byte);
+        acc_bits += 8;
+
+        while acc_bits >= bits_per_symbol {
+            acc_bits -= bits_per_symbol;
+            let index = (acc >> acc_bits) & symbol_mask;
+            out.push(alphabet.symbols[index as usize]);
+        }
+
+        acc &= (1u32 << acc_bits) - 1;
+    }
+
+    if acc_bits > 0 {
+        let index = (acc << (bits_per_symbol - acc_bits)) & symbol_mask;
+        out.push(alphabet.symbols[index as usize]);
+    }
+
+    out
+}
+
+/// Decodes `s`, which was produced by [`encode_with`] using the same `alphabet`.
+///
+/// ## Errors
+///
+/// - `DecodeError::InvalidCharacter` if `s` contains a character that is not part of `alphabet`.
+/// - `DecodeError::InvalidLength` if the bits left over past the last full byte amount to one
+///   whole symbol's worth or more.
+pub fn decode_with(alphabet: &Alphabet, s: &str) -> Result<Vec<u8>, DecodeError> {
+    let bits_per_symbol = alphabet.bits_per_symbol();
+
+    let indices = s
+        .chars()
+        .enumerate()
+        .map(|(pos, char)| {
+            alphabet
+                .index_of(char)
+                .ok_or(DecodeError::InvalidCharacter { pos, char })
+        })
+        .collect::<Result<Vec<usize>, DecodeError>>()?;
+
+    // `encode_with` pads its final symbol with zero bits when `bits_per_symbol` doesn't divide
+    // 8 evenly, so a trailing remainder is only valid if it's smaller than one whole symbol;
+    // anything larger means the input itself is misaligned rather than just padded.
+    let total_bits = indices.len() * bits_per_symbol as usize;
+    if total_bits % 8 >= bits_per_symbol as usize {
+        return Err(DecodeError::InvalidLength {
+            length: indices.len(),
+        });
+    }
+
+    let mut acc = 0u32;
+    let mut acc_bits = 0u32;
+    let mut out = Vec::with_capacity(indices.len() * bits_per_symbol as usize / 8);
+
+    for index in indices {
+        acc = (acc << bits_per_symbol) | index as u32;
+        acc_bits += bits_per_symbol;
+
+        while acc_bits >= 8 {
+            acc_bits -= 8;
+            out.push(((acc >> acc_bits) & 0xff) as u8);
+        }
+
+        acc &= (1u32 << acc_bits) - 1;
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base4_roundtrip() {
+        let alphabet = base4_alphabet();
+        let data = vec![10, 200, 0, 255];
+        let encoded = encode_with(&alphabet, &data);
+
+        assert_eq!(Ok(data), decode_with(&alphabet, &encoded));
+    }
+
+    #[test]
+    fn test_base8_roundtrip() {
+        let alphabet = base8_alphabet();
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let encoded = encode_with(&alphabet, &data);
+
+        assert_eq!(Ok(data), decode_with(&alphabet, &encoded));
+    }
+
+    #[test]
+    fn test_base8_roundtrip_requires_padding() {
+        let alphabet = base8_alphabet();
+
+        for len in [1, 2, 4, 5, 7] {
+            let data = (0..len as u8).collect::<Vec<u8>>();
+            let encoded = encode_with(&alphabet, &data);
+
+            assert_eq!(Ok(data), decode_with(&alphabet, &encoded), "len = {len}");
+        }
+    }
+
+    #[test]
+    fn test_default_alphabet_matches_encode() {
+        let alphabet = default_alphabet();
+        let data = vec![10, 10];
+
+        assert_eq!(crate::encode(&data), encode_with(&alphabet, &data));
+    }
+
+    #[test]
+    fn test_decode_with_invalid_character() {
+        let alphabet = base4_alphabet();
+
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                pos: 0,
+                char: '?'
+            }),
+            decode_with(&alphabet, "?")
+        );
+    }
+
+    #[test]
+    fn test_decode_with_invalid_length() {
+        let alphabet = base4_alphabet();
+        let encoded = encode_with(&alphabet, &[10]);
+        let truncated = encoded.chars().take(3).collect::<String>();
+
+        assert_eq!(
+            Err(DecodeError::InvalidLength { length: 3 }),
+            decode_with(&alphabet, &truncated)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "alphabet length must be a power of two")]
+    fn test_alphabet_rejects_non_power_of_two() {
+        Alphabet::new(vec!['a', 'b', 'c']);
+    }
+}
@@ -0,0 +1,133 @@
+//! A checksummed codec that appends a trailing 4-byte checksum to detect corruption or
+//! tampering in the whitespace stream, following the trailing-checksum convention used by
+//! address encoders. By default the checksum is the first 4 bytes of a double hash of the
+//! payload; enable the `crc32` feature to use a CRC32 instead.
+
+use crate::DecodeError;
+
+/// Encodes `data` followed by a trailing 4-byte checksum of `data`, so a corrupted or tampered
+/// stream can be detected on decode.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whitespace::checksum::{encode_checked, decode_checked};
+///
+/// let data = vec![10, 10];
+/// let encoded = encode_checked(&data);
+///
+/// assert_eq!(Ok(data), decode_checked(&encoded));
+/// ```
+pub fn encode_checked(data: &[u8]) -> String {
+    let mut buffer = data.to_vec();
+    buffer.extend_from_slice(&checksum(data).to_be_bytes());
+
+    crate::encode(&buffer)
+}
+
+/// Decodes `s`, which was produced by [`encode_checked`], verifying the trailing checksum
+/// before returning the payload.
+///
+/// ## Errors
+///
+/// - `DecodeError::InvalidCharacter` / `DecodeError::InvalidLength` if `s` is not validly
+///   whitespace-encoded.
+/// - `DecodeError::UnexpectedEof` if the decoded bytes are too short to contain a checksum.
+/// - `DecodeError::ChecksumMismatch` if the recomputed checksum does not match the trailing one.
+pub fn decode_checked(s: &str) -> Result<Vec<u8>, DecodeError> {
+    let mut bytes = crate::decode(s)?;
+
+    if bytes.len() < 4 {
+        return Err(DecodeError::UnexpectedEof {
+            expected: 4,
+            got: bytes.len(),
+        });
+    }
+
+    let checksum_start = bytes.len() - 4;
+    let found = u32::from_be_bytes(bytes[checksum_start..].try_into().unwrap());
+    bytes.truncate(checksum_start);
+
+    let expected = checksum(&bytes);
+    if expected != found {
+        return Err(DecodeError::ChecksumMismatch { expected, found });
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(not(feature = "crc32"))]
+fn checksum(data: &[u8]) -> u32 {
+    fnv1a32(&fnv1a32(data).to_be_bytes())
+}
+
+/// The non-cryptographic hash behind the default double-hash checksum.
+#[cfg(not(feature = "crc32"))]
+fn fnv1a32(data: &[u8]) -> u32 {
+    const OFFSET_BASIS: u32 = 0x811c9dc5;
+    const PRIME: u32 = 0x01000193;
+
+    data.iter()
+        .fold(OFFSET_BASIS, |hash, &byte| (hash ^ byte as u32).wrapping_mul(PRIME))
+}
+
+#[cfg(feature = "crc32")]
+fn checksum(data: &[u8]) -> u32 {
+    const POLYNOMIAL: u32 = 0xedb88320;
+
+    data.iter()
+        .fold(0xffffffffu32, |crc, &byte| {
+            let mut crc = crc ^ byte as u32;
+
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ POLYNOMIAL
+                } else {
+                    crc >> 1
+                };
+            }
+
+            crc
+        })
+        ^ 0xffffffff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_roundtrip() {
+        let data = vec![1, 2, 3, 4, 5];
+        let encoded = encode_checked(&data);
+
+        assert_eq!(Ok(data), decode_checked(&encoded));
+    }
+
+    #[test]
+    fn test_checked_detects_tampering() {
+        let data = vec![10, 10];
+        let encoded = encode_checked(&data);
+        let mut bytes = crate::decode(&encoded).unwrap();
+        bytes[0] ^= 0xff;
+        let tampered = crate::encode(&bytes);
+
+        assert!(matches!(
+            decode_checked(&tampered),
+            Err(DecodeError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_checked_unexpected_eof() {
+        let encoded = crate::encode(&[1, 2]);
+
+        assert_eq!(
+            Err(DecodeError::UnexpectedEof {
+                expected: 4,
+                got: 2
+            }),
+            decode_checked(&encoded)
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! Length-prefixed framing so a decoded blob can report truncation precisely and be embedded
+//! inside a larger whitespace-encoded document.
+
+use crate::DecodeError;
+
+/// Encodes `data` prefixed with a little-endian base-128 varint byte-count header, so the
+/// receiver knows exactly how many payload bytes to expect.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whitespace::framing::{encode_framed, decode_framed};
+///
+/// let data = vec![10, 10];
+/// let encoded = encode_framed(&data);
+///
+/// assert_eq!(Ok(data), decode_framed(&encoded));
+/// ```
+pub fn encode_framed(data: &[u8]) -> String {
+    let mut buffer = encode_varint(data.len());
+    buffer.extend_from_slice(data);
+
+    crate::encode(&buffer)
+}
+
+/// Decodes `s`, which was produced by [`encode_framed`]. Any characters trailing the declared
+/// payload length are ignored, so a framed blob can be embedded inside a larger document.
+///
+/// ## Errors
+///
+/// - `DecodeError::InvalidCharacter` / `DecodeError::InvalidLength` if `s` is not validly
+///   whitespace-encoded.
+/// - `DecodeError::InvalidLength` if the varint header runs past the width of a `usize`.
+/// - `DecodeError::UnexpectedEof` if the stream ends before the varint header is complete or
+///   before the declared payload length is reached.
+pub fn decode_framed(s: &str) -> Result<Vec<u8>, DecodeError> {
+    let bytes = crate::decode(s)?;
+    let (length, header_len) = decode_varint(&bytes)?;
+
+    let payload = &bytes[header_len..];
+    if payload.len() < length {
+        return Err(DecodeError::UnexpectedEof {
+            expected: length,
+            got: payload.len(),
+        });
+    }
+
+    Ok(payload[..length].to_vec())
+}
+
+fn encode_varint(mut value: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    loop {
+        let mut group = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value != 0 {
+            group |= 0x80;
+        }
+
+        bytes.push(group);
+
+        if value == 0 {
+            break;
+        }
+    }
+
+    bytes
+}
+
+fn decode_varint(bytes: &[u8]) -> Result<(usize, usize), DecodeError> {
+    let mut value = 0usize;
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        let group = ((byte & 0x7f) as usize)
+            .checked_shl(7 * i as u32)
+            .ok_or(DecodeError::InvalidLength { length: i })?;
+        value |= group;
+
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+
+    Err(DecodeError::UnexpectedEof {
+        expected: 1,
+        got: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_framed_roundtrip() {
+        let data = vec![1, 2, 3, 4, 5];
+        let encoded = encode_framed(&data);
+
+        assert_eq!(Ok(data), decode_framed(&encoded));
+    }
+
+    #[test]
+    fn test_framed_roundtrip_large_payload() {
+        let data = (0..=255u8).cycle().take(300).collect::<Vec<u8>>();
+        let encoded = encode_framed(&data);
+
+        assert_eq!(Ok(data), decode_framed(&encoded));
+    }
+
+    #[test]
+    fn test_framed_ignores_trailing_characters() {
+        let data = vec![10, 10];
+        let mut encoded = encode_framed(&data);
+        encoded.push_str(&crate::encode(&[99]));
+
+        assert_eq!(Ok(data), decode_framed(&encoded));
+    }
+
+    #[test]
+    fn test_framed_unexpected_eof() {
+        let data = vec![1, 2, 3, 4, 5];
+        let encoded = encode_framed(&data);
+        let bytes = crate::decode(&encoded).unwrap();
+        let truncated = crate::encode(&bytes[..bytes.len() - 2]);
+
+        assert_eq!(
+            Err(DecodeError::UnexpectedEof {
+                expected: 5,
+                got: 3
+            }),
+            decode_framed(&truncated)
+        );
+    }
+
+    #[test]
+    fn test_framed_malformed_varint_does_not_panic() {
+        let malformed = crate::encode(&[0x80u8; 12]);
+
+        assert!(matches!(
+            decode_framed(&malformed),
+            Err(DecodeError::InvalidLength { .. })
+        ));
+    }
+}
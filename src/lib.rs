@@ -1,5 +1,12 @@
 use thiserror::Error;
 
+pub mod alphabet;
+pub mod checksum;
+pub mod framing;
+pub mod stego;
+pub mod stream;
+pub mod traits;
+
 const HIGH: char = '\u{0020}';
 const LOW: char = '\u{200b}';
 
@@ -9,6 +16,9 @@ const LOW: char = '\u{200b}';
 ///  - \u{0020} (whitespace) represents a high bit
 ///  - \u{200b} (zero width whitespace) represents a low bit
 ///
+/// To encode structured data directly instead of a raw byte slice, see the
+/// [`WhitespaceEncode`](traits::WhitespaceEncode) trait.
+///
 /// ## Examples
 ///
 /// ```rust
@@ -91,12 +101,43 @@ pub fn decode(input: &str) -> Result<Vec<u8>, DecodeError> {
     Ok(bytes)
 }
 
-#[derive(Error, Debug, Eq, PartialEq)]
+#[derive(Error, Debug)]
 pub enum DecodeError {
     #[error("Invalid input length {length}. Must be divisible through 8")]
     InvalidLength { length: usize },
     #[error("Invalid character {char} at position {pos}")]
     InvalidCharacter { pos: usize, char: char },
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Unexpected end of input, expected {expected} bytes but got {got}")]
+    UnexpectedEof { expected: usize, got: usize },
+    #[error("Checksum mismatch: expected {expected:08x}, found {found:08x}")]
+    ChecksumMismatch { expected: u32, found: u32 },
+    #[error("Decoded bytes are not valid UTF-8")]
+    InvalidUtf8,
+}
+
+impl PartialEq for DecodeError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::InvalidLength { length: a }, Self::InvalidLength { length: b }) => a == b,
+            (
+                Self::InvalidCharacter { pos: a, char: ac },
+                Self::InvalidCharacter { pos: b, char: bc },
+            ) => a == b && ac == bc,
+            (Self::Io(a), Self::Io(b)) => a.kind() == b.kind(),
+            (
+                Self::UnexpectedEof { expected: a, got: ag },
+                Self::UnexpectedEof { expected: b, got: bg },
+            ) => a == b && ag == bg,
+            (
+                Self::ChecksumMismatch { expected: a, found: af },
+                Self::ChecksumMismatch { expected: b, found: bf },
+            ) => a == b && af == bf,
+            (Self::InvalidUtf8, Self::InvalidUtf8) => true,
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
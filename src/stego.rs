@@ -0,0 +1,172 @@
+//! Text steganography helpers that hide an encoded payload inside ordinary cover text and
+//! recover it again, for the classic "paste a document, get the secret back" use case.
+
+use crate::DecodeError;
+
+/// Represents a set `1` bit in the stego payload. Unlike the crate-level `HIGH`/`LOW` symbols
+/// (one of which is a literal space), both stego symbols are invisible zero-width characters so
+/// they don't collide with ordinary whitespace in prose.
+const ONE: char = '\u{200b}';
+/// Represents a clear `0` bit in the stego payload. See [`ONE`].
+const ZERO: char = '\u{200c}';
+
+fn encode_invisible(data: &[u8]) -> String {
+    data.iter()
+        .copied()
+        .fold(String::with_capacity(data.len() * 8), |buffer, byte| {
+            (0..8).rev().fold(buffer, |mut buffer, bit| {
+                buffer.push(match byte & (1u8 << bit) {
+                    0 => ZERO,
+                    _ => ONE,
+                });
+
+                buffer
+            })
+        })
+}
+
+/// Decodes `input`, silently skipping any character that is neither a stego `ONE` nor `ZERO`
+/// symbol instead of erroring on the first one encountered. Useful when `input` is a whole
+/// document with hidden symbols interleaved into visible cover text.
+///
+/// ## Errors
+///
+/// Returns `DecodeError::InvalidLength` if the number of valid symbols found does not align to
+/// a byte boundary.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whitespace::stego::{decode_lenient, embed};
+///
+/// let cover = "hello, whitespace world!";
+/// let hidden = embed(cover, &[10, 10]);
+///
+/// assert_eq!(Ok(vec![10, 10]), decode_lenient(&hidden));
+/// ```
+pub fn decode_lenient(input: &str) -> Result<Vec<u8>, DecodeError> {
+    let bits = input
+        .chars()
+        .filter_map(|char| match char {
+            ZERO => Some(0u8),
+            ONE => Some(1u8),
+            _ => None,
+        })
+        .collect::<Vec<u8>>();
+
+    if bits.len() % 8 != 0 {
+        return Err(DecodeError::InvalidLength { length: bits.len() });
+    }
+
+    Ok(bits
+        .chunks_exact(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .enumerate()
+                .fold(0u8, |byte, (pos, bit)| byte | bit << pos)
+        })
+        .collect())
+}
+
+/// Hides `data` inside `cover` by interleaving one invisible symbol after each visible character
+/// of `cover`. Any symbols left over once `cover` is exhausted are appended as trailing
+/// whitespace. Because the hidden symbols are zero-width, `cover` can be ordinary prose,
+/// spaces included.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whitespace::stego::{embed, extract};
+///
+/// let cover = "the quick brown fox jumps over the lazy dog";
+/// let data = vec![10, 10];
+/// let hidden = embed(cover, &data);
+///
+/// assert_eq!(Ok(data), extract(&hidden));
+/// ```
+pub fn embed(cover: &str, data: &[u8]) -> String {
+    let mut symbols = encode_invisible(data).chars().collect::<Vec<char>>().into_iter();
+    let mut result = String::with_capacity(cover.len() + symbols.len());
+
+    for char in cover.chars() {
+        result.push(char);
+
+        if let Some(symbol) = symbols.next() {
+            result.push(symbol);
+        }
+    }
+
+    result.extend(symbols);
+
+    result
+}
+
+/// Recovers the payload hidden in `s` by a previous call to [`embed`] (or any document with
+/// stego `ONE`/`ZERO` symbols interleaved into visible text).
+///
+/// ## Errors
+///
+/// See [`decode_lenient`].
+pub fn extract(s: &str) -> Result<Vec<u8>, DecodeError> {
+    decode_lenient(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_lenient_skips_invalid_characters() {
+        let encoded = encode_invisible(&[10, 10]);
+        let cover = format!("hello {} world", encoded);
+
+        assert_eq!(Ok(vec![10, 10]), decode_lenient(&cover));
+    }
+
+    #[test]
+    fn test_decode_lenient_invalid_length() {
+        let encoded = encode_invisible(&[10, 10]);
+        let truncated = encoded.chars().take(15).collect::<String>();
+
+        assert_eq!(
+            Err(DecodeError::InvalidLength { length: 15 }),
+            decode_lenient(&truncated)
+        );
+    }
+
+    #[test]
+    fn test_embed_extract_roundtrip() {
+        let cover = "the quick brown fox jumps over the lazy dog";
+        let data = vec![1, 2, 3, 42];
+
+        let hidden = embed(cover, &data);
+
+        assert_eq!(Ok(data), extract(&hidden));
+    }
+
+    #[test]
+    fn test_embed_extract_roundtrip_on_ordinary_prose() {
+        let cover = "the quick brown fox";
+        let data = vec![10, 20, 30];
+
+        let hidden = embed(cover, &data);
+
+        assert_eq!(Ok(data), extract(&hidden));
+    }
+
+    #[test]
+    fn test_embed_keeps_cover_text_readable() {
+        let cover = "hi there";
+        let data = vec![10];
+
+        let hidden = embed(cover, &data);
+        let visible = hidden
+            .chars()
+            .filter(|c| *c != ONE && *c != ZERO)
+            .collect::<String>();
+
+        assert_eq!(cover, visible);
+    }
+}
@@ -0,0 +1,191 @@
+//! Streaming variants of [`crate::encode`] and [`crate::decode`] that avoid holding the
+//! whole payload in memory, for piping large files or network streams through the codec.
+
+use crate::{DecodeError, HIGH, LOW};
+use std::io::{self, Read, Write};
+
+/// Encodes `data` as whitespace, writing each byte's 8 symbols to `w` as they are produced
+/// instead of building the whole output `String` up front.
+///
+/// ## Errors
+///
+/// Returns any [`std::io::Error`] produced while writing to `w`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whitespace::stream::encode_to;
+///
+/// let data = vec![10, 10];
+/// let mut out = Vec::new();
+/// encode_to(data, &mut out).unwrap();
+///
+/// assert_eq!(String::from_utf8(out).unwrap(), whitespace::encode(&[10, 10]));
+/// ```
+pub fn encode_to<W: Write>(data: impl IntoIterator<Item = u8>, w: &mut W) -> io::Result<()> {
+    let mut utf8_buf = [0u8; 4];
+
+    for byte in data {
+        for bit in (0..8).rev() {
+            let symbol = match byte & (1u8 << bit) {
+                0 => LOW,
+                _ => HIGH,
+            };
+
+            w.write_all(symbol.encode_utf8(&mut utf8_buf).as_bytes())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Lazily decodes whitespace symbols read from `R`, yielding one decoded byte per 8 valid
+/// symbols read.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whitespace::stream::Decoder;
+///
+/// let encoded = whitespace::encode(&[10, 10]);
+/// let decoded = Decoder::new(encoded.as_bytes())
+///     .collect::<Result<Vec<u8>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(vec![10, 10], decoded);
+/// ```
+pub struct Decoder<R> {
+    reader: R,
+    pos: usize,
+}
+
+impl<R: Read> Decoder<R> {
+    /// Creates a new `Decoder` reading whitespace symbols from `reader`.
+    pub fn new(reader: R) -> Self {
+        Decoder { reader, pos: 0 }
+    }
+
+    fn read_char(&mut self) -> io::Result<Option<char>> {
+        let mut first = [0u8; 1];
+
+        if self.reader.read(&mut first)? == 0 {
+            return Ok(None);
+        }
+
+        let len = utf8_len(first[0])?;
+        let mut buf = [0u8; 4];
+        buf[0] = first[0];
+
+        if len > 1 {
+            self.reader.read_exact(&mut buf[1..len])?;
+        }
+
+        let symbol = std::str::from_utf8(&buf[..len])
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+            .chars()
+            .next()
+            .expect("decoded exactly one char");
+
+        Ok(Some(symbol))
+    }
+}
+
+impl<R: Read> Iterator for Decoder<R> {
+    type Item = Result<u8, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut byte = 0u8;
+
+        for bit in (0..8).rev() {
+            let char = match self.read_char() {
+                Ok(Some(char)) => char,
+                Ok(None) if bit == 7 => return None,
+                Ok(None) => return Some(Err(DecodeError::InvalidLength { length: self.pos })),
+                Err(err) => return Some(Err(DecodeError::Io(err))),
+            };
+
+            let pos = self.pos;
+            self.pos += 1;
+
+            byte |= match char {
+                LOW => 0,
+                HIGH => 1,
+                _ => return Some(Err(DecodeError::InvalidCharacter { pos, char })),
+            } << bit;
+        }
+
+        Some(Ok(byte))
+    }
+}
+
+fn utf8_len(first_byte: u8) -> io::Result<usize> {
+    match first_byte {
+        0x00..=0x7f => Ok(1),
+        0xc0..=0xdf => Ok(2),
+        0xe0..=0xef => Ok(3),
+        0xf0..=0xf7 => Ok(4),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid UTF-8 lead byte 0x{first_byte:02x}"),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_to() {
+        let data = vec![10, 10];
+        let mut out = Vec::new();
+        encode_to(data.clone(), &mut out).unwrap();
+
+        assert_eq!(crate::encode(&data).into_bytes(), out);
+    }
+
+    #[test]
+    fn test_decoder_roundtrip() {
+        let data = vec![10, 10, 255, 0];
+        let encoded = crate::encode(&data);
+
+        let decoded = Decoder::new(encoded.as_bytes())
+            .collect::<Result<Vec<u8>, _>>()
+            .unwrap();
+
+        assert_eq!(data, decoded);
+    }
+
+    #[test]
+    fn test_decoder_invalid_character() {
+        let encoded = "\u{200b}?\u{200b}\u{200b}\u{0020}\u{200b}\u{0020}\u{200b}";
+
+        let err = Decoder::new(encoded.as_bytes())
+            .collect::<Result<Vec<u8>, _>>()
+            .unwrap_err();
+
+        assert_eq!(DecodeError::InvalidCharacter { pos: 1, char: '?' }, err);
+    }
+
+    #[test]
+    fn test_decoder_invalid_length() {
+        let encoded = "\u{200b}\u{200b}\u{200b}\u{200b}\u{0020}\u{200b}\u{0020}";
+
+        let err = Decoder::new(encoded.as_bytes())
+            .collect::<Result<Vec<u8>, _>>()
+            .unwrap_err();
+
+        assert_eq!(DecodeError::InvalidLength { length: 7 }, err);
+    }
+
+    #[test]
+    fn test_decoder_rejects_stray_continuation_byte() {
+        let bytes = [0x80u8];
+
+        let err = Decoder::new(&bytes[..])
+            .collect::<Result<Vec<u8>, _>>()
+            .unwrap_err();
+
+        assert!(matches!(err, DecodeError::Io(_)));
+    }
+}
@@ -0,0 +1,230 @@
+//! `WhitespaceEncode`/`WhitespaceDecode` traits so callers can whitespace-encode structured
+//! data directly instead of flattening it to a byte slice by hand first.
+
+use crate::{DecodeError, HIGH, LOW};
+
+/// Types that can append their whitespace-encoded representation to an output buffer.
+pub trait WhitespaceEncode {
+    fn encode(&self, out: &mut String);
+}
+
+/// Types that can be reconstructed from a stream of decoded bits (`0`/`1`), one bit per
+/// whitespace symbol.
+pub trait WhitespaceDecode: Sized {
+    fn decode(bits: &mut impl Iterator<Item = u8>) -> Result<Self, DecodeError>;
+}
+
+/// Encodes `value` using its [`WhitespaceEncode`] implementation.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whitespace::traits::{encode, decode};
+///
+/// let encoded = encode(&42u32);
+///
+/// assert_eq!(Ok(42u32), decode(&encoded));
+/// ```
+pub fn encode<T: WhitespaceEncode>(value: &T) -> String {
+    let mut out = String::new();
+    value.encode(&mut out);
+    out
+}
+
+/// Decodes `input` into a `T` using its [`WhitespaceDecode`] implementation.
+pub fn decode<T: WhitespaceDecode>(input: &str) -> Result<T, DecodeError> {
+    let mut bits = chars_to_bits(input)?.into_iter();
+    T::decode(&mut bits)
+}
+
+fn chars_to_bits(input: &str) -> Result<Vec<u8>, DecodeError> {
+    input
+        .chars()
+        .enumerate()
+        .map(|(pos, char)| match char {
+            LOW => Ok(0),
+            HIGH => Ok(1),
+            _ => Err(DecodeError::InvalidCharacter { pos, char }),
+        })
+        .collect()
+}
+
+fn read_byte(bits: &mut impl Iterator<Item = u8>) -> Result<u8, DecodeError> {
+    (0..8).rev().try_fold(0u8, |byte, shift| {
+        let bit = bits
+            .next()
+            .ok_or(DecodeError::UnexpectedEof { expected: 1, got: 0 })?;
+
+        Ok(byte | (bit << shift))
+    })
+}
+
+impl WhitespaceEncode for u8 {
+    fn encode(&self, out: &mut String) {
+        out.push_str(&crate::encode(&[*self]));
+    }
+}
+
+impl WhitespaceDecode for u8 {
+    fn decode(bits: &mut impl Iterator<Item = u8>) -> Result<Self, DecodeError> {
+        read_byte(bits)
+    }
+}
+
+macro_rules! impl_whitespace_int {
+    ($ty:ty, $len:expr) => {
+        impl WhitespaceEncode for $ty {
+            fn encode(&self, out: &mut String) {
+                out.push_str(&crate::encode(&self.to_be_bytes()));
+            }
+        }
+
+        impl WhitespaceDecode for $ty {
+            fn decode(bits: &mut impl Iterator<Item = u8>) -> Result<Self, DecodeError> {
+                let mut bytes = [0u8; $len];
+                for byte in bytes.iter_mut() {
+                    *byte = u8::decode(bits)?;
+                }
+
+                Ok(<$ty>::from_be_bytes(bytes))
+            }
+        }
+    };
+}
+
+impl_whitespace_int!(u16, 2);
+impl_whitespace_int!(u32, 4);
+impl_whitespace_int!(u64, 8);
+
+impl WhitespaceEncode for &[u8] {
+    fn encode(&self, out: &mut String) {
+        (self.len() as u32).encode(out);
+        out.push_str(&crate::encode(self));
+    }
+}
+
+impl WhitespaceEncode for Vec<u8> {
+    fn encode(&self, out: &mut String) {
+        self.as_slice().encode(out);
+    }
+}
+
+impl WhitespaceDecode for Vec<u8> {
+    fn decode(bits: &mut impl Iterator<Item = u8>) -> Result<Self, DecodeError> {
+        let len = u32::decode(bits)? as usize;
+
+        (0..len).map(|_| u8::decode(bits)).collect()
+    }
+}
+
+impl WhitespaceEncode for String {
+    fn encode(&self, out: &mut String) {
+        self.as_bytes().to_vec().encode(out);
+    }
+}
+
+impl WhitespaceDecode for String {
+    fn decode(bits: &mut impl Iterator<Item = u8>) -> Result<Self, DecodeError> {
+        let bytes = Vec::<u8>::decode(bits)?;
+
+        String::from_utf8(bytes).map_err(|_| DecodeError::InvalidUtf8)
+    }
+}
+
+impl<T: WhitespaceEncode> WhitespaceEncode for Option<T> {
+    fn encode(&self, out: &mut String) {
+        match self {
+            Some(value) => {
+                out.push(HIGH);
+                value.encode(out);
+            }
+            None => out.push(LOW),
+        }
+    }
+}
+
+impl<T: WhitespaceDecode> WhitespaceDecode for Option<T> {
+    fn decode(bits: &mut impl Iterator<Item = u8>) -> Result<Self, DecodeError> {
+        let discriminant = bits
+            .next()
+            .ok_or(DecodeError::UnexpectedEof { expected: 1, got: 0 })?;
+
+        match discriminant {
+            1 => Ok(Some(T::decode(bits)?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<A: WhitespaceEncode, B: WhitespaceEncode> WhitespaceEncode for (A, B) {
+    fn encode(&self, out: &mut String) {
+        self.0.encode(out);
+        self.1.encode(out);
+    }
+}
+
+impl<A: WhitespaceDecode, B: WhitespaceDecode> WhitespaceDecode for (A, B) {
+    fn decode(bits: &mut impl Iterator<Item = u8>) -> Result<Self, DecodeError> {
+        Ok((A::decode(bits)?, B::decode(bits)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u8_roundtrip() {
+        let encoded = encode(&200u8);
+
+        assert_eq!(Ok(200u8), decode(&encoded));
+    }
+
+    #[test]
+    fn test_u32_roundtrip() {
+        let encoded = encode(&0xdeadbeefu32);
+
+        assert_eq!(Ok(0xdeadbeefu32), decode(&encoded));
+    }
+
+    #[test]
+    fn test_vec_roundtrip() {
+        let data = vec![1u8, 2, 3, 4, 5];
+        let encoded = encode(&data);
+
+        assert_eq!(Ok(data), decode(&encoded));
+    }
+
+    #[test]
+    fn test_string_roundtrip() {
+        let data = "hello whitespace".to_string();
+        let encoded = encode(&data);
+
+        assert_eq!(Ok(data), decode(&encoded));
+    }
+
+    #[test]
+    fn test_string_decode_invalid_utf8() {
+        let invalid = vec![0xff, 0xfe];
+        let encoded = encode(&invalid);
+
+        assert_eq!(Err(DecodeError::InvalidUtf8), decode::<String>(&encoded));
+    }
+
+    #[test]
+    fn test_option_roundtrip() {
+        let some: Option<u8> = Some(42);
+        let none: Option<u8> = None;
+
+        assert_eq!(Ok(some), decode(&encode(&some)));
+        assert_eq!(Ok(none), decode(&encode(&none)));
+    }
+
+    #[test]
+    fn test_tuple_roundtrip() {
+        let data = (7u8, 300u32);
+        let encoded = encode(&data);
+
+        assert_eq!(Ok(data), decode(&encoded));
+    }
+}